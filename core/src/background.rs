@@ -1,14 +1,16 @@
 use crate::gradient::{self, Gradient};
-use crate::Color;
+use crate::image::{self, FilterMethod};
+use crate::{Color, Rectangle, Size};
 
 /// The background of some element.
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum Background {
     /// A solid color.
     Color(Color),
     /// Linearly interpolate between several colors.
     Gradient(Gradient),
-    // TODO: Add image variant
+    /// An image painted over the bounds of the element.
+    Image(Image),
 }
 
 impl Background {
@@ -20,10 +22,101 @@ impl Background {
             Self::Gradient(gradient) => {
                 Self::Gradient(gradient.scale_alpha(factor))
             }
+            Self::Image(image) => Self::Image(image.scale_alpha(factor)),
         }
     }
 }
 
+/// An image drawn as the [`Background`] of some element.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Image {
+    /// The handle of the image to paint.
+    pub handle: image::Handle,
+    /// The strategy used to place the image within the bounds.
+    pub fit: Fit,
+    /// The filtering strategy applied when the image is scaled.
+    pub filter_method: FilterMethod,
+    /// The opacity of the image, where `0.0` is transparent and `1.0` is
+    /// fully opaque.
+    pub opacity: f32,
+}
+
+impl Image {
+    /// Creates a new [`Image`] background from the given handle, stretched to
+    /// fill the bounds with linear filtering.
+    pub fn new(handle: impl Into<image::Handle>) -> Self {
+        Image {
+            handle: handle.into(),
+            fit: Fit::default(),
+            filter_method: FilterMethod::default(),
+            opacity: 1.0,
+        }
+    }
+
+    /// Sets the [`Fit`] of the [`Image`] background.
+    pub fn fit(mut self, fit: Fit) -> Self {
+        self.fit = fit;
+        self
+    }
+
+    /// Sets the [`FilterMethod`] of the [`Image`] background.
+    pub fn filter_method(mut self, filter_method: FilterMethod) -> Self {
+        self.filter_method = filter_method;
+        self
+    }
+
+    /// Scales the opacity of the [`Image`] by the given factor.
+    pub fn scale_alpha(mut self, factor: f32) -> Self {
+        self.opacity *= factor;
+        self
+    }
+
+    /// Resolves the destination [`Rectangle`] at which a source image of the
+    /// given `image_size` should be drawn to fill `bounds` according to the
+    /// [`Fit`] of this [`Image`].
+    ///
+    /// A renderer uses this to size the textured quad so the image scales
+    /// cleanly; the returned rectangle is centered within `bounds` and may
+    /// exceed it for [`Fit::Cover`] (the renderer is expected to clip to
+    /// `bounds`). [`Fit::Tile`] returns `bounds` unchanged, leaving the
+    /// renderer to repeat the texture at `image_size`.
+    pub fn fitted(&self, image_size: Size, bounds: Rectangle) -> Rectangle {
+        let scale = match self.fit {
+            Fit::Fill | Fit::Tile => {
+                return bounds;
+            }
+            Fit::Cover => (bounds.width / image_size.width)
+                .max(bounds.height / image_size.height),
+            Fit::Contain => (bounds.width / image_size.width)
+                .min(bounds.height / image_size.height),
+        };
+
+        let width = image_size.width * scale;
+        let height = image_size.height * scale;
+
+        Rectangle {
+            x: bounds.x + (bounds.width - width) / 2.0,
+            y: bounds.y + (bounds.height - height) / 2.0,
+            width,
+            height,
+        }
+    }
+}
+
+/// The strategy used to paint a background [`Image`] within its bounds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Fit {
+    /// Stretch the image to fill the bounds, ignoring its aspect ratio.
+    #[default]
+    Fill,
+    /// Scale the image to cover the bounds, preserving its aspect ratio.
+    Cover,
+    /// Scale the image to fit inside the bounds, preserving its aspect ratio.
+    Contain,
+    /// Repeat the image at its native size to tile the bounds.
+    Tile,
+}
+
 impl From<Color> for Background {
     fn from(color: Color) -> Self {
         Background::Color(color)
@@ -41,3 +134,15 @@ impl From<gradient::Linear> for Background {
         Background::Gradient(Gradient::Linear(gradient))
     }
 }
+
+impl From<Image> for Background {
+    fn from(image: Image) -> Self {
+        Background::Image(image)
+    }
+}
+
+impl From<image::Handle> for Background {
+    fn from(handle: image::Handle) -> Self {
+        Background::Image(Image::new(handle))
+    }
+}
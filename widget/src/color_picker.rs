@@ -0,0 +1,544 @@
+//! Display an interactive HSV color picker.
+pub use crate::slider::{Handle, HandleShape, Status};
+
+use crate::core::border::Border;
+use crate::core::event::{self, Event};
+use crate::core::gradient;
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::renderer;
+use crate::core::touch;
+use crate::core::widget::tree::{self, Tree};
+use crate::core::{
+    Clipboard, Color, Element, Layout, Length, Point, Radians, Rectangle,
+    Shell, Size, Theme, Widget,
+};
+
+/// An interactive HSV color picker.
+///
+/// A [`ColorPicker`] is composed of two interactive surfaces: a square
+/// saturation/value area — whose background is the full gradient for the
+/// current hue — and a narrow hue strip spanning `0..360°`. Dragging over
+/// either surface converts the picked `(h, s, v)` triplet to RGB and publishes
+/// the resulting [`Color`] through `on_change`.
+///
+/// # Example
+/// ```no_run
+/// # type ColorPicker<'a, Message> = iced_widget::ColorPicker<'a, Message>;
+/// # use iced_widget::core::Color;
+/// #
+/// #[derive(Clone)]
+/// pub enum Message {
+///     ColorPicked(Color),
+/// }
+///
+/// ColorPicker::new(Color::WHITE, Message::ColorPicked);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct ColorPicker<'a, Message, Theme = crate::Theme> {
+    value: Color,
+    on_change: Box<dyn Fn(Color) -> Message + 'a>,
+    on_release: Option<Message>,
+    width: Length,
+    height: Length,
+    style: Style<Theme>,
+}
+
+impl<'a, Message, Theme> ColorPicker<'a, Message, Theme>
+where
+    Message: Clone,
+{
+    /// The default side of the saturation/value area of a [`ColorPicker`].
+    pub const DEFAULT_SIZE: f32 = 128.0;
+
+    /// The width of the hue strip of a [`ColorPicker`].
+    pub const HUE_STRIP_WIDTH: f32 = 16.0;
+
+    /// The spacing between the saturation/value area and the hue strip.
+    pub const SPACING: f32 = 8.0;
+
+    /// Creates a new [`ColorPicker`].
+    ///
+    /// It expects:
+    ///   * the current [`Color`] of the [`ColorPicker`]
+    ///   * a function that will be called when a new [`Color`] is picked.
+    pub fn new<F>(value: Color, on_change: F) -> Self
+    where
+        Theme: DefaultStyle,
+        F: 'a + Fn(Color) -> Message,
+    {
+        ColorPicker {
+            value,
+            on_change: Box::new(on_change),
+            on_release: None,
+            width: Length::Fixed(
+                Self::DEFAULT_SIZE + Self::SPACING + Self::HUE_STRIP_WIDTH,
+            ),
+            height: Length::Fixed(Self::DEFAULT_SIZE),
+            style: Theme::default_style(),
+        }
+    }
+
+    /// Sets the release message of the [`ColorPicker`].
+    ///
+    /// This is called when the mouse is released from either surface.
+    pub fn on_release(mut self, on_release: Message) -> Self {
+        self.on_release = Some(on_release);
+        self
+    }
+
+    /// Sets the width of the [`ColorPicker`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`ColorPicker`].
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the style of the [`ColorPicker`].
+    pub fn style(mut self, style: fn(&Theme, Status) -> Appearance) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Returns the rectangles of the saturation/value area and the hue strip,
+    /// in that order, for the given `bounds`.
+    fn surfaces(bounds: Rectangle) -> (Rectangle, Rectangle) {
+        let strip = Rectangle {
+            x: bounds.x + bounds.width - Self::HUE_STRIP_WIDTH,
+            y: bounds.y,
+            width: Self::HUE_STRIP_WIDTH,
+            height: bounds.height,
+        };
+
+        let square = Rectangle {
+            width: bounds.width - Self::HUE_STRIP_WIDTH - Self::SPACING,
+            ..bounds
+        };
+
+        (square, strip)
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for ColorPicker<'a, Message, Theme>
+where
+    Message: Clone,
+    Renderer: crate::core::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    fn layout(
+        &self,
+        _tree: &mut Tree,
+        _renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        layout::atomic(limits, self.width, self.height)
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+        let (square, strip) = Self::surfaces(layout.bounds());
+
+        // Resolve the current HSV, recovering the hue and saturation cached
+        // in [`State`] when the stored color is gray or black — `to_hsv` would
+        // otherwise report a meaningless hue of `0` and snap the picker to red.
+        let (actual_h, actual_s, v) = to_hsv(self.value);
+        let h = if actual_s == 0.0 || v == 0.0 {
+            state.hue
+        } else {
+            actual_h
+        };
+        let s = if actual_s == 0.0 { state.saturation } else { actual_s };
+
+        let change = |new_value: Color| {
+            if new_value != self.value {
+                shell.publish((self.on_change)(new_value));
+
+                self.value = new_value;
+            }
+        };
+
+        // Picks a new color from a surface, returning it alongside the hue and
+        // saturation that produced it so they can be cached in [`State`].
+        let pick = |surface: Surface, position: Point| -> (Color, f32, f32) {
+            match surface {
+                Surface::Square => {
+                    let saturation = ((position.x - square.x) / square.width)
+                        .clamp(0.0, 1.0);
+                    let value = 1.0
+                        - ((position.y - square.y) / square.height)
+                            .clamp(0.0, 1.0);
+
+                    (from_hsv(h, saturation, value), h, saturation)
+                }
+                Surface::Hue => {
+                    let hue = ((position.y - strip.y) / strip.height)
+                        .clamp(0.0, 1.0)
+                        * 360.0;
+
+                    (from_hsv(hue, s, v), hue, s)
+                }
+            }
+        };
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                if let Some(position) = cursor.position() {
+                    let surface = if square.contains(position) {
+                        Some(Surface::Square)
+                    } else if strip.contains(position) {
+                        Some(Surface::Hue)
+                    } else {
+                        None
+                    };
+
+                    if let Some(surface) = surface {
+                        let (color, hue, saturation) = pick(surface, position);
+
+                        state.dragging = Some(surface);
+                        state.hue = hue;
+                        state.saturation = saturation;
+                        change(color);
+
+                        return event::Status::Captured;
+                    }
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. })
+            | Event::Touch(touch::Event::FingerLost { .. }) => {
+                if state.dragging.is_some() {
+                    if let Some(on_release) = self.on_release.clone() {
+                        shell.publish(on_release);
+                    }
+                    state.dragging = None;
+
+                    return event::Status::Captured;
+                }
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. })
+            | Event::Touch(touch::Event::FingerMoved { .. }) => {
+                if let Some(surface) = state.dragging {
+                    if let Some(position) = cursor.position() {
+                        let (color, hue, saturation) = pick(surface, position);
+
+                        state.hue = hue;
+                        state.saturation = saturation;
+                        change(color);
+
+                        return event::Status::Captured;
+                    }
+                }
+            }
+            _ => {}
+        }
+
+        event::Status::Ignored
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+        let (square, strip) = Self::surfaces(bounds);
+        let is_mouse_over = cursor.is_over(bounds);
+
+        let style = (self.style)(
+            theme,
+            if state.dragging.is_some() {
+                Status::Dragged
+            } else if is_mouse_over {
+                Status::Hovered
+            } else {
+                Status::Active
+            },
+        );
+
+        let (actual_h, actual_s, value) = to_hsv(self.value);
+        let hue = if actual_s == 0.0 || value == 0.0 {
+            state.hue
+        } else {
+            actual_h
+        };
+        let saturation = if actual_s == 0.0 {
+            state.saturation
+        } else {
+            actual_s
+        };
+
+        // Saturation/value area: a horizontal white-to-hue gradient overlaid
+        // with a vertical transparent-to-black gradient.
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: square,
+                border: style.border,
+                ..renderer::Quad::default()
+            },
+            gradient::Linear::new(Radians(std::f32::consts::FRAC_PI_2))
+                .add_stop(0.0, Color::WHITE)
+                .add_stop(1.0, from_hsv(hue, 1.0, 1.0)),
+        );
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: square,
+                border: style.border,
+                ..renderer::Quad::default()
+            },
+            gradient::Linear::new(Radians(std::f32::consts::PI))
+                .add_stop(0.0, Color::from_rgba(0.0, 0.0, 0.0, 0.0))
+                .add_stop(1.0, Color::BLACK),
+        );
+
+        // Hue strip: seven linear gradient stops at 0/60/…/360°.
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: strip,
+                border: style.border,
+                ..renderer::Quad::default()
+            },
+            gradient::Linear::new(Radians(std::f32::consts::PI))
+                .add_stop(0.0, from_hsv(0.0, 1.0, 1.0))
+                .add_stop(1.0 / 6.0, from_hsv(60.0, 1.0, 1.0))
+                .add_stop(2.0 / 6.0, from_hsv(120.0, 1.0, 1.0))
+                .add_stop(3.0 / 6.0, from_hsv(180.0, 1.0, 1.0))
+                .add_stop(4.0 / 6.0, from_hsv(240.0, 1.0, 1.0))
+                .add_stop(5.0 / 6.0, from_hsv(300.0, 1.0, 1.0))
+                .add_stop(1.0, from_hsv(360.0, 1.0, 1.0)),
+        );
+
+        let (handle_width, handle_border_radius) = match style.handle.shape {
+            HandleShape::Circle { radius } => (radius * 2.0, radius.into()),
+            HandleShape::Rectangle {
+                width,
+                border_radius,
+            } => (f32::from(width), border_radius),
+        };
+
+        // Handle over the saturation/value area.
+        let square_handle = Rectangle {
+            x: square.x + saturation * square.width - handle_width / 2.0,
+            y: square.y + (1.0 - value) * square.height - handle_width / 2.0,
+            width: handle_width,
+            height: handle_width,
+        };
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: square_handle,
+                border: Border {
+                    radius: handle_border_radius,
+                    width: style.handle.border_width,
+                    color: style.handle.border_color,
+                },
+                ..renderer::Quad::default()
+            },
+            self.value,
+        );
+
+        // Handle over the hue strip.
+        let hue_handle = Rectangle {
+            x: strip.x,
+            y: strip.y + (hue / 360.0) * strip.height
+                - style.handle.border_width,
+            width: strip.width,
+            height: style.handle.border_width.max(2.0) * 2.0,
+        };
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: hue_handle,
+                border: Border {
+                    width: style.handle.border_width.max(1.0),
+                    color: style.handle.border_color,
+                    ..Border::default()
+                },
+                ..renderer::Quad::default()
+            },
+            Color::TRANSPARENT,
+        );
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State>();
+        let is_mouse_over = cursor.is_over(layout.bounds());
+
+        if state.dragging.is_some() {
+            mouse::Interaction::Grabbing
+        } else if is_mouse_over {
+            mouse::Interaction::Grab
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<ColorPicker<'a, Message, Theme>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: Clone + 'a,
+    Theme: 'a,
+    Renderer: crate::core::Renderer + 'a,
+{
+    fn from(
+        picker: ColorPicker<'a, Message, Theme>,
+    ) -> Element<'a, Message, Theme, Renderer> {
+        Element::new(picker)
+    }
+}
+
+/// The surface of a [`ColorPicker`] currently being dragged.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Surface {
+    /// The saturation/value area.
+    Square,
+    /// The hue strip.
+    Hue,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+struct State {
+    dragging: Option<Surface>,
+    /// The last hue (`0..360`) picked by the user, preserved so it survives
+    /// dragging saturation or value down to a gray or black color.
+    hue: f32,
+    /// The last saturation (`0..1`) picked by the user, preserved for the same
+    /// reason.
+    saturation: f32,
+}
+
+/// Converts an `(h, s, v)` triplet — with `h` in `0..360` and `s`, `v` in
+/// `0..1` — to an opaque [`Color`].
+fn from_hsv(h: f32, s: f32, v: f32) -> Color {
+    let c = v * s;
+    let h_prime = h / 60.0;
+    let x = c * (1.0 - (h_prime.rem_euclid(2.0) - 1.0).abs());
+    let m = v - c;
+
+    let (r, g, b) = match h_prime as u8 {
+        0 => (c, x, 0.0),
+        1 => (x, c, 0.0),
+        2 => (0.0, c, x),
+        3 => (0.0, x, c),
+        4 => (x, 0.0, c),
+        _ => (c, 0.0, x),
+    };
+
+    Color::from_rgb(r + m, g + m, b + m)
+}
+
+/// Converts a [`Color`] to an `(h, s, v)` triplet, with `h` in `0..360` and
+/// `s`, `v` in `0..1`.
+fn to_hsv(color: Color) -> (f32, f32, f32) {
+    let max = color.r.max(color.g).max(color.b);
+    let min = color.r.min(color.g).min(color.b);
+    let delta = max - min;
+
+    let hue = if delta == 0.0 {
+        0.0
+    } else if max == color.r {
+        60.0 * (((color.g - color.b) / delta).rem_euclid(6.0))
+    } else if max == color.g {
+        60.0 * (((color.b - color.r) / delta) + 2.0)
+    } else {
+        60.0 * (((color.r - color.g) / delta) + 4.0)
+    };
+
+    let saturation = if max == 0.0 { 0.0 } else { delta / max };
+
+    (hue, saturation, max)
+}
+
+/// The appearance of a [`ColorPicker`].
+#[derive(Debug, Clone, Copy)]
+pub struct Appearance {
+    /// The [`Border`] of the surfaces.
+    pub border: Border,
+    /// The appearance of the [`Handle`] drawn over each surface.
+    pub handle: Handle,
+}
+
+/// The style of a [`ColorPicker`].
+pub type Style<Theme> = fn(&Theme, Status) -> Appearance;
+
+/// The default style of a [`ColorPicker`].
+pub trait DefaultStyle {
+    /// Returns the default style of a [`ColorPicker`].
+    fn default_style() -> Style<Self>;
+}
+
+impl DefaultStyle for Theme {
+    fn default_style() -> Style<Self> {
+        default
+    }
+}
+
+impl DefaultStyle for Appearance {
+    fn default_style() -> Style<Self> {
+        |appearance, _status| *appearance
+    }
+}
+
+/// The default style of a [`ColorPicker`].
+pub fn default(theme: &Theme, _status: Status) -> Appearance {
+    let palette = theme.extended_palette();
+
+    Appearance {
+        border: Border {
+            radius: 2.0.into(),
+            width: 1.0,
+            color: palette.background.strong.color,
+        },
+        handle: Handle {
+            shape: HandleShape::Circle { radius: 6.0 },
+            color: Color::WHITE,
+            border_color: Color::WHITE,
+            border_width: 2.0,
+        },
+    }
+}
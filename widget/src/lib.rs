@@ -0,0 +1,15 @@
+//! Use the built-in widgets or create your own.
+#![cfg_attr(docsrs, feature(doc_auto_cfg))]
+pub use iced_core as core;
+
+pub mod color_picker;
+pub mod slider;
+pub mod vertical_slider;
+pub mod xy_pad;
+
+pub use color_picker::ColorPicker;
+pub use slider::Slider;
+pub use vertical_slider::VerticalSlider;
+pub use xy_pad::XYPad;
+
+pub use crate::core::theme::Theme;
@@ -9,8 +9,8 @@ use crate::core::renderer;
 use crate::core::touch;
 use crate::core::widget::tree::{self, Tree};
 use crate::core::{
-    Border, Clipboard, Color, Element, Layout, Length, Pixels, Point,
-    Rectangle, Shell, Size, Theme, Widget,
+    Background, Border, Clipboard, Color, Element, Layout, Length, Pixels,
+    Point, Rectangle, Shell, Size, Theme, Widget,
 };
 
 use std::ops::RangeInclusive;
@@ -49,6 +49,8 @@ pub struct Slider<'a, T, Message, Theme = crate::Theme> {
     on_release: Option<Message>,
     width: Length,
     height: f32,
+    ticks: bool,
+    snap: bool,
     style: Style<Theme>,
 }
 
@@ -95,6 +97,8 @@ where
             on_release: None,
             width: Length::Fill,
             height: Self::DEFAULT_HEIGHT,
+            ticks: false,
+            snap: false,
             style: Theme::default_style(),
         }
     }
@@ -149,6 +153,33 @@ where
         self.shift_step = Some(shift_step.into());
         self
     }
+
+    /// Draws a tick mark at every [`step`] along the rail of the [`Slider`].
+    ///
+    /// This is handy for coarse, discrete sliders where the selectable
+    /// positions would otherwise be invisible. If a [`default`] value is set,
+    /// the tick nearest to it is drawn taller, as a stronger detent.
+    ///
+    /// [`step`]: Self::step
+    /// [`default`]: Self::default
+    pub fn ticks(mut self) -> Self {
+        self.ticks = true;
+        self
+    }
+
+    /// Locks the handle of the [`Slider`] onto the [`step`] grid even while a
+    /// finer [`shift_step`] is held.
+    ///
+    /// Values are always rounded to [`step`], so an ordinary drag already lands
+    /// on ticks; `snap` additionally prevents the shift modifier from nudging
+    /// the handle in between them.
+    ///
+    /// [`step`]: Self::step
+    /// [`shift_step`]: Self::shift_step
+    pub fn snap(mut self) -> Self {
+        self.snap = true;
+        self
+    }
 }
 
 impl<'a, T, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
@@ -205,7 +236,9 @@ where
             } else if cursor_position.x >= bounds.x + bounds.width {
                 Some(*self.range.end())
             } else {
-                let step = if state.keyboard_modifiers.shift() {
+                let step = if self.snap {
+                    self.step
+                } else if state.keyboard_modifiers.shift() {
                     self.shift_step.unwrap_or(self.step)
                 } else {
                     self.step
@@ -228,7 +261,9 @@ where
         };
 
         let increment = |value: T| -> Option<T> {
-            let step = if state.keyboard_modifiers.shift() {
+            let step = if self.snap {
+                self.step
+            } else if state.keyboard_modifiers.shift() {
                 self.shift_step.unwrap_or(self.step)
             } else {
                 self.step
@@ -246,7 +281,9 @@ where
         };
 
         let decrement = |value: T| -> Option<T> {
-            let step = if state.keyboard_modifiers.shift() {
+            let step = if self.snap {
+                self.step
+            } else if state.keyboard_modifiers.shift() {
                 self.shift_step.unwrap_or(self.step)
             } else {
                 self.step
@@ -368,13 +405,16 @@ where
                 } => (f32::from(width), bounds.height, border_radius),
             };
 
-        let value = self.value.into() as f32;
         let (range_start, range_end) = {
             let (start, end) = self.range.clone().into_inner();
 
             (start.into() as f32, end.into() as f32)
         };
 
+        let step = self.step.into() as f32;
+
+        let value = self.value.into() as f32;
+
         let offset = if range_start >= range_end {
             0.0
         } else {
@@ -395,7 +435,7 @@ where
                 border: Border::rounded(style.rail.border_radius),
                 ..renderer::Quad::default()
             },
-            style.rail.colors.0,
+            style.rail.backgrounds.0.clone(),
         );
 
         renderer.fill_quad(
@@ -409,9 +449,55 @@ where
                 border: Border::rounded(style.rail.border_radius),
                 ..renderer::Quad::default()
             },
-            style.rail.colors.1,
+            style.rail.backgrounds.1.clone(),
         );
 
+        if self.ticks {
+            if let Some(tick) = style.tick {
+                if step > 0.0 && range_end > range_start {
+                    let default_index = self.default.map(|default| {
+                        (((default.into() as f32) - range_start) / step).round()
+                    });
+
+                    let count = ((range_end - range_start) / step).round();
+                    let mut i = 0.0;
+
+                    while i <= count {
+                        let tick_value = range_start + i * step;
+                        let tick_offset = (bounds.width - handle_width)
+                            * (tick_value - range_start)
+                            / (range_end - range_start)
+                            + handle_width / 2.0;
+
+                        let is_detent =
+                            default_index.is_some_and(|index| index == i);
+
+                        let height = if is_detent {
+                            tick.height * 1.5
+                        } else {
+                            tick.height
+                        };
+
+                        renderer.fill_quad(
+                            renderer::Quad {
+                                bounds: Rectangle {
+                                    x: bounds.x + tick_offset
+                                        - tick.width / 2.0,
+                                    y: rail_y - height / 2.0,
+                                    width: tick.width,
+                                    height,
+                                },
+                                ..renderer::Quad::default()
+                            },
+                            tick.color,
+                        );
+
+                        i += 1.0;
+                    }
+                }
+            }
+        }
+
         renderer.fill_quad(
             renderer::Quad {
                 bounds: Rectangle {
@@ -486,12 +572,15 @@ pub enum Status {
 }
 
 /// The appearance of a slider.
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone)]
 pub struct Appearance {
-    /// The colors of the rail of the slider.
+    /// The appearance of the rail of the slider.
     pub rail: Rail,
     /// The appearance of the [`Handle`] of the slider.
     pub handle: Handle,
+    /// The appearance of the tick marks of the slider, if enabled through
+    /// [`Slider::ticks`].
+    pub tick: Option<Tick>,
 }
 
 impl Appearance {
@@ -506,10 +595,15 @@ impl Appearance {
 }
 
 /// The appearance of a slider rail
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone)]
 pub struct Rail {
-    /// The colors of the rail of the slider.
-    pub colors: (Color, Color),
+    /// The backgrounds of the filled and unfilled portions of the rail.
+    ///
+    /// Each portion may be a solid [`Color`] or a [`Gradient`], so the active
+    /// part of the rail can be painted with a smooth ramp.
+    ///
+    /// [`Gradient`]: crate::core::Gradient
+    pub backgrounds: (Background, Background),
     /// The width of the stroke of a slider rail.
     pub width: f32,
     /// The border radius of the corners of the rail.
@@ -529,6 +623,17 @@ pub struct Handle {
     pub border_color: Color,
 }
 
+/// The appearance of a tick mark of a slider.
+#[derive(Debug, Clone, Copy)]
+pub struct Tick {
+    /// The [`Color`] of the tick mark.
+    pub color: Color,
+    /// The width of the tick mark.
+    pub width: f32,
+    /// The height of the tick mark.
+    pub height: f32,
+}
+
 /// The shape of the handle of a slider.
 #[derive(Debug, Clone, Copy)]
 pub enum HandleShape {
@@ -563,7 +668,7 @@ impl DefaultStyle for Theme {
 
 impl DefaultStyle for Appearance {
     fn default_style() -> Style<Self> {
-        |appearance, _status| *appearance
+        |appearance, _status| appearance.clone()
     }
 }
 
@@ -579,7 +684,10 @@ pub fn default(theme: &Theme, status: Status) -> Appearance {
 
     Appearance {
         rail: Rail {
-            colors: (color, palette.secondary.base.color),
+            backgrounds: (
+                Background::Color(color),
+                Background::Color(palette.secondary.base.color),
+            ),
             width: 4.0,
             border_radius: 2.0.into(),
         },
@@ -589,5 +697,10 @@ pub fn default(theme: &Theme, status: Status) -> Appearance {
             border_color: Color::TRANSPARENT,
             border_width: 0.0,
         },
+        tick: Some(Tick {
+            color: palette.background.strong.color,
+            width: 1.0,
+            height: 8.0,
+        }),
     }
 }
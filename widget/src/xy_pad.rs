@@ -0,0 +1,572 @@
+//! Display an interactive selector of a point from two ranges of values.
+use std::ops::RangeInclusive;
+
+pub use crate::slider::{Handle, HandleShape, Status};
+
+use crate::core::border::Border;
+use crate::core::event::{self, Event};
+use crate::core::keyboard;
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::renderer;
+use crate::core::touch;
+use crate::core::widget::tree::{self, Tree};
+use crate::core::{
+    Clipboard, Color, Element, Layout, Length, Point, Rectangle, Shell, Size,
+    Theme, Widget,
+};
+
+/// A rectangular area and a handle that selects a point from two ranges of
+/// values.
+///
+/// An [`XYPad`] generalizes a [`Slider`] into two dimensions: it maps the
+/// horizontal position of its handle to one range and the vertical position to
+/// another, inverting the vertical axis so that moving up yields a larger
+/// value.
+///
+/// The [`XYPad`] is handy for pan/bias controls, envelope points and any other
+/// parameter surface that exposes two coupled values.
+///
+/// [`Slider`]: crate::Slider
+///
+/// # Example
+/// ```no_run
+/// # type XYPad<'a, X, Y, Message> = iced_widget::XYPad<'a, X, Y, Message>;
+/// #
+/// #[derive(Clone)]
+/// pub enum Message {
+///     Moved((f32, f32)),
+/// }
+///
+/// XYPad::new(0.0..=1.0, 0.0..=1.0, (0.5, 0.5), Message::Moved);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct XYPad<'a, X, Y, Message, Theme = crate::Theme> {
+    x_range: RangeInclusive<X>,
+    y_range: RangeInclusive<Y>,
+    value: (X, Y),
+    default: Option<(X, Y)>,
+    x_step: X,
+    x_shift_step: Option<X>,
+    y_step: Y,
+    y_shift_step: Option<Y>,
+    on_change: Box<dyn Fn((X, Y)) -> Message + 'a>,
+    on_release: Option<Message>,
+    width: Length,
+    height: Length,
+    style: Style<Theme>,
+}
+
+impl<'a, X, Y, Message, Theme> XYPad<'a, X, Y, Message, Theme>
+where
+    X: Copy + From<u8> + PartialOrd,
+    Y: Copy + From<u8> + PartialOrd,
+    Message: Clone,
+{
+    /// The default side of an [`XYPad`].
+    pub const DEFAULT_SIZE: f32 = 128.0;
+
+    /// Creates a new [`XYPad`].
+    ///
+    /// It expects:
+    ///   * an inclusive range of possible values for each axis
+    ///   * the current `(x, y)` value of the [`XYPad`]
+    ///   * a function that will be called when the [`XYPad`] is dragged. It
+    ///   receives the new `(x, y)` value of the [`XYPad`] and must produce a
+    ///   `Message`.
+    pub fn new<F>(
+        x_range: RangeInclusive<X>,
+        y_range: RangeInclusive<Y>,
+        value: (X, Y),
+        on_change: F,
+    ) -> Self
+    where
+        Theme: DefaultStyle,
+        F: 'a + Fn((X, Y)) -> Message,
+    {
+        let x = if value.0 < *x_range.start() {
+            *x_range.start()
+        } else if value.0 > *x_range.end() {
+            *x_range.end()
+        } else {
+            value.0
+        };
+
+        let y = if value.1 < *y_range.start() {
+            *y_range.start()
+        } else if value.1 > *y_range.end() {
+            *y_range.end()
+        } else {
+            value.1
+        };
+
+        XYPad {
+            value: (x, y),
+            default: None,
+            x_range,
+            y_range,
+            x_step: X::from(1),
+            x_shift_step: None,
+            y_step: Y::from(1),
+            y_shift_step: None,
+            on_change: Box::new(on_change),
+            on_release: None,
+            width: Length::Fixed(Self::DEFAULT_SIZE),
+            height: Length::Fixed(Self::DEFAULT_SIZE),
+            style: Theme::default_style(),
+        }
+    }
+
+    /// Sets the optional default value for the [`XYPad`].
+    ///
+    /// If set, the [`XYPad`] will reset to this point when ctrl-clicked or
+    /// command-clicked.
+    pub fn default(mut self, default: impl Into<(X, Y)>) -> Self {
+        self.default = Some(default.into());
+        self
+    }
+
+    /// Sets the release message of the [`XYPad`].
+    ///
+    /// This is called when the mouse is released from the pad.
+    pub fn on_release(mut self, on_release: Message) -> Self {
+        self.on_release = Some(on_release);
+        self
+    }
+
+    /// Sets the width of the [`XYPad`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`XYPad`].
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the style of the [`XYPad`].
+    pub fn style(mut self, style: fn(&Theme, Status) -> Appearance) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Sets the step size of the horizontal axis of the [`XYPad`].
+    pub fn x_step(mut self, x_step: impl Into<X>) -> Self {
+        self.x_step = x_step.into();
+        self
+    }
+
+    /// Sets the step size of the vertical axis of the [`XYPad`].
+    pub fn y_step(mut self, y_step: impl Into<Y>) -> Self {
+        self.y_step = y_step.into();
+        self
+    }
+
+    /// Sets the optional "shift" step of the horizontal axis of the [`XYPad`].
+    pub fn x_shift_step(mut self, x_shift_step: impl Into<X>) -> Self {
+        self.x_shift_step = Some(x_shift_step.into());
+        self
+    }
+
+    /// Sets the optional "shift" step of the vertical axis of the [`XYPad`].
+    pub fn y_shift_step(mut self, y_shift_step: impl Into<Y>) -> Self {
+        self.y_shift_step = Some(y_shift_step.into());
+        self
+    }
+}
+
+impl<'a, X, Y, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for XYPad<'a, X, Y, Message, Theme>
+where
+    X: Copy + Into<f64> + num_traits::FromPrimitive,
+    Y: Copy + Into<f64> + num_traits::FromPrimitive,
+    Message: Clone,
+    Renderer: crate::core::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    fn layout(
+        &self,
+        _tree: &mut Tree,
+        _renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        layout::atomic(limits, self.width, self.height)
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+
+        let is_dragging = state.is_dragging;
+
+        // Snaps a raw position along an axis onto the axis' step, mirroring the
+        // `locate` logic of [`Slider`] for each dimension.
+        let snap = |percent: f64,
+                    range: &RangeInclusive<f64>,
+                    step: f64|
+         -> f64 {
+            let start = *range.start();
+            let end = *range.end();
+
+            if percent <= 0.0 {
+                start
+            } else if percent >= 1.0 {
+                end
+            } else {
+                let steps = (percent * (end - start) / step).round();
+                steps * step + start
+            }
+        };
+
+        let locate = |cursor_position: Point| -> Option<(X, Y)> {
+            let bounds = layout.bounds();
+
+            let (x_step, y_step) = if state.keyboard_modifiers.shift() {
+                (
+                    self.x_shift_step.unwrap_or(self.x_step).into(),
+                    self.y_shift_step.unwrap_or(self.y_step).into(),
+                )
+            } else {
+                (self.x_step.into(), self.y_step.into())
+            };
+
+            let percent_x =
+                f64::from(cursor_position.x - bounds.x) / f64::from(bounds.width);
+            let percent_y = 1.0
+                - f64::from(cursor_position.y - bounds.y)
+                    / f64::from(bounds.height);
+
+            let x = snap(
+                percent_x,
+                &((*self.x_range.start()).into()
+                    ..=(*self.x_range.end()).into()),
+                x_step,
+            );
+            let y = snap(
+                percent_y,
+                &((*self.y_range.start()).into()
+                    ..=(*self.y_range.end()).into()),
+                y_step,
+            );
+
+            Some((X::from_f64(x)?, Y::from_f64(y)?))
+        };
+
+        let change = |new_value: (X, Y)| {
+            let changed = (self.value.0.into() - new_value.0.into()).abs()
+                > f64::EPSILON
+                || (self.value.1.into() - new_value.1.into()).abs()
+                    > f64::EPSILON;
+
+            if changed {
+                shell.publish((self.on_change)(new_value));
+
+                self.value = new_value;
+            }
+        };
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                if let Some(cursor_position) =
+                    cursor.position_over(layout.bounds())
+                {
+                    if state.keyboard_modifiers.command() {
+                        let _ = self.default.map(change);
+                        state.is_dragging = false;
+                    } else {
+                        let _ = locate(cursor_position).map(change);
+                        state.is_dragging = true;
+                    }
+
+                    return event::Status::Captured;
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. })
+            | Event::Touch(touch::Event::FingerLost { .. }) => {
+                if is_dragging {
+                    if let Some(on_release) = self.on_release.clone() {
+                        shell.publish(on_release);
+                    }
+                    state.is_dragging = false;
+
+                    return event::Status::Captured;
+                }
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. })
+            | Event::Touch(touch::Event::FingerMoved { .. }) => {
+                if is_dragging {
+                    let _ = cursor.position().and_then(locate).map(change);
+
+                    return event::Status::Captured;
+                }
+            }
+            Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) => {
+                state.keyboard_modifiers = modifiers;
+            }
+            _ => {}
+        }
+
+        event::Status::Ignored
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+        let is_mouse_over = cursor.is_over(bounds);
+
+        let style = (self.style)(
+            theme,
+            if state.is_dragging {
+                Status::Dragged
+            } else if is_mouse_over {
+                Status::Hovered
+            } else {
+                Status::Active
+            },
+        );
+
+        let (handle_width, handle_height, handle_border_radius) =
+            match style.handle.shape {
+                HandleShape::Circle { radius } => {
+                    (radius * 2.0, radius * 2.0, radius.into())
+                }
+                HandleShape::Rectangle {
+                    width,
+                    border_radius,
+                } => (f32::from(width), f32::from(width), border_radius),
+            };
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border: style.border,
+                ..renderer::Quad::default()
+            },
+            style.background,
+        );
+
+        let percent = |value: f64, range: RangeInclusive<f64>| -> f32 {
+            let (start, end) = range.into_inner();
+
+            if start >= end {
+                0.0
+            } else {
+                ((value - start) / (end - start)) as f32
+            }
+        };
+
+        let percent_x = percent(
+            self.value.0.into(),
+            (*self.x_range.start()).into()..=(*self.x_range.end()).into(),
+        );
+        let percent_y = percent(
+            self.value.1.into(),
+            (*self.y_range.start()).into()..=(*self.y_range.end()).into(),
+        );
+
+        let handle_x =
+            bounds.x + (bounds.width - handle_width) * percent_x
+                + handle_width / 2.0;
+        let handle_y = bounds.y
+            + (bounds.height - handle_height) * (1.0 - percent_y)
+            + handle_height / 2.0;
+
+        // Vertical crosshair rail.
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: Rectangle {
+                    x: handle_x - style.rail.width / 2.0,
+                    y: bounds.y,
+                    width: style.rail.width,
+                    height: bounds.height,
+                },
+                ..renderer::Quad::default()
+            },
+            style.rail.colors.0,
+        );
+
+        // Horizontal crosshair rail.
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: Rectangle {
+                    x: bounds.x,
+                    y: handle_y - style.rail.width / 2.0,
+                    width: bounds.width,
+                    height: style.rail.width,
+                },
+                ..renderer::Quad::default()
+            },
+            style.rail.colors.1,
+        );
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: Rectangle {
+                    x: handle_x - handle_width / 2.0,
+                    y: handle_y - handle_height / 2.0,
+                    width: handle_width,
+                    height: handle_height,
+                },
+                border: Border {
+                    radius: handle_border_radius,
+                    width: style.handle.border_width,
+                    color: style.handle.border_color,
+                },
+                ..renderer::Quad::default()
+            },
+            style.handle.color,
+        );
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+        let is_mouse_over = cursor.is_over(bounds);
+
+        if state.is_dragging {
+            mouse::Interaction::Grabbing
+        } else if is_mouse_over {
+            mouse::Interaction::Grab
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+}
+
+impl<'a, X, Y, Message, Theme, Renderer> From<XYPad<'a, X, Y, Message, Theme>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    X: Copy + Into<f64> + num_traits::FromPrimitive + 'a,
+    Y: Copy + Into<f64> + num_traits::FromPrimitive + 'a,
+    Message: Clone + 'a,
+    Theme: 'a,
+    Renderer: crate::core::Renderer + 'a,
+{
+    fn from(
+        pad: XYPad<'a, X, Y, Message, Theme>,
+    ) -> Element<'a, Message, Theme, Renderer> {
+        Element::new(pad)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+struct State {
+    is_dragging: bool,
+    keyboard_modifiers: keyboard::Modifiers,
+}
+
+/// The appearance of an [`XYPad`].
+#[derive(Debug, Clone, Copy)]
+pub struct Appearance {
+    /// The [`Color`] of the surface behind the crosshair.
+    pub background: Color,
+    /// The [`Border`] of the surface.
+    pub border: Border,
+    /// The colors of the two crosshair rails.
+    pub rail: Rail,
+    /// The appearance of the [`Handle`] of the pad.
+    pub handle: Handle,
+}
+
+/// The appearance of the crosshair rails of an [`XYPad`].
+#[derive(Debug, Clone, Copy)]
+pub struct Rail {
+    /// The colors of the vertical and horizontal rails.
+    pub colors: (Color, Color),
+    /// The width of the stroke of a rail.
+    pub width: f32,
+}
+
+/// The style of an [`XYPad`].
+pub type Style<Theme> = fn(&Theme, Status) -> Appearance;
+
+/// The default style of an [`XYPad`].
+pub trait DefaultStyle {
+    /// Returns the default style of an [`XYPad`].
+    fn default_style() -> Style<Self>;
+}
+
+impl DefaultStyle for Theme {
+    fn default_style() -> Style<Self> {
+        default
+    }
+}
+
+impl DefaultStyle for Appearance {
+    fn default_style() -> Style<Self> {
+        |appearance, _status| *appearance
+    }
+}
+
+/// The default style of an [`XYPad`].
+pub fn default(theme: &Theme, status: Status) -> Appearance {
+    let palette = theme.extended_palette();
+
+    let color = match status {
+        Status::Active => palette.primary.strong.color,
+        Status::Hovered => palette.primary.base.color,
+        Status::Dragged => palette.primary.strong.color,
+    };
+
+    Appearance {
+        background: palette.background.weak.color,
+        border: Border {
+            radius: 2.0.into(),
+            width: 1.0,
+            color: palette.background.strong.color,
+        },
+        rail: Rail {
+            colors: (color, color),
+            width: 1.0,
+        },
+        handle: Handle {
+            shape: HandleShape::Circle { radius: 7.0 },
+            color,
+            border_color: Color::TRANSPARENT,
+            border_width: 0.0,
+        },
+    }
+}